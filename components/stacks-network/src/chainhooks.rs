@@ -1,4 +1,6 @@
-use chainhook_sdk::chainhooks::types::{ChainhookSpecificationNetworkMap, ChainhookStore};
+use chainhook_sdk::chainhooks::types::{
+    ChainhookSpecificationNetworkMap, ChainhookStore, HookAction,
+};
 use chainhook_sdk::types::{BitcoinNetwork, StacksNetwork};
 use clarinet_files::FileLocation;
 use std::fs::File;
@@ -37,11 +39,15 @@ pub fn load_chainhooks(
             Ok(hook) => match hook {
                 ChainhookSpecificationNetworkMap::Bitcoin(predicate) => {
                     let mut spec = predicate.into_specification_for_network(&networks.0)?;
+                    validate_hook_action(&mut spec.action)
+                        .map_err(|e| format!("{} action invalid: {}", relative_path, e))?;
                     spec.enabled = true;
                     bitcoin_chainhooks.push(spec)
                 }
                 ChainhookSpecificationNetworkMap::Stacks(predicate) => {
                     let mut spec = predicate.into_specification_for_network(&networks.1)?;
+                    validate_hook_action(&mut spec.action)
+                        .map_err(|e| format!("{} action invalid: {}", relative_path, e))?;
                     spec.enabled = true;
                     stacks_chainhooks.push(spec)
                 }
@@ -55,6 +61,30 @@ pub fn load_chainhooks(
     })
 }
 
+fn validate_hook_action(action: &mut HookAction) -> Result<(), String> {
+    if let HookAction::HttpPost(http) = action {
+        http.url = parse_http_hook_url(&http.url)?;
+    }
+    Ok(())
+}
+
+/// Parse and normalize the url of an `http_post` action, so that malformed
+/// urls are reported when the chainhook is loaded rather than at delivery time.
+/// `Url::parse` already rejects http and https urls without a host.
+pub fn parse_http_hook_url(url: &str) -> Result<String, String> {
+    let parsed = reqwest::Url::parse(url).map_err(|e| format!("invalid url {}: {}", url, e))?;
+    match parsed.scheme() {
+        "http" | "https" => {}
+        scheme => {
+            return Err(format!(
+                "unsupported url scheme '{}' in {} (expected http or https)",
+                scheme, url
+            ))
+        }
+    }
+    Ok(parsed.to_string())
+}
+
 fn get_chainhooks_files(
     manifest_location: &FileLocation,
 ) -> Result<Vec<(PathBuf, String)>, String> {
@@ -82,3 +112,79 @@ fn get_chainhooks_files(
 
     Ok(hook_paths)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chainhook_sdk::chainhooks::types::{FileHook, HttpHook};
+
+    #[test]
+    fn test_parse_http_hook_url() {
+        assert_eq!(
+            parse_http_hook_url("http://localhost:3000/api/events"),
+            Ok("http://localhost:3000/api/events".to_string())
+        );
+        assert_eq!(
+            parse_http_hook_url("HTTPS://Example.com"),
+            Ok("https://example.com/".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_http_hook_url_rejects_unsupported_scheme() {
+        let err = parse_http_hook_url("ftp://example.com/events").unwrap_err();
+        assert!(err.contains("unsupported url scheme 'ftp'"));
+
+        // a missing scheme is parsed as a `localhost:` url
+        let err = parse_http_hook_url("localhost:3000/api/events").unwrap_err();
+        assert!(err.contains("unsupported url scheme 'localhost'"));
+    }
+
+    #[test]
+    fn test_parse_http_hook_url_rejects_missing_host() {
+        let err = parse_http_hook_url("http://").unwrap_err();
+        assert!(err.contains("invalid url http://: empty host"));
+    }
+
+    #[test]
+    fn test_validate_hook_action_normalizes_http_url() {
+        let mut action = HookAction::HttpPost(HttpHook {
+            url: "http://localhost:3000".to_string(),
+            authorization_header: "Bearer token".to_string(),
+        });
+        validate_hook_action(&mut action).unwrap();
+        match action {
+            HookAction::HttpPost(http) => {
+                assert_eq!(http.url, "http://localhost:3000/");
+                assert_eq!(http.authorization_header, "Bearer token");
+            }
+            _ => panic!("expected an http_post action"),
+        }
+    }
+
+    #[test]
+    fn test_validate_hook_action_rejects_invalid_http_url() {
+        let mut action = HookAction::HttpPost(HttpHook {
+            url: "ftp://example.com/events".to_string(),
+            authorization_header: "".to_string(),
+        });
+        let err = validate_hook_action(&mut action).unwrap_err();
+        assert!(err.contains("unsupported url scheme 'ftp'"));
+    }
+
+    #[test]
+    fn test_validate_hook_action_ignores_other_actions() {
+        let mut action = HookAction::Noop;
+        assert!(validate_hook_action(&mut action).is_ok());
+        assert!(matches!(action, HookAction::Noop));
+
+        let mut action = HookAction::FileAppend(FileHook {
+            path: "not a url".to_string(),
+        });
+        assert!(validate_hook_action(&mut action).is_ok());
+        match action {
+            HookAction::FileAppend(file) => assert_eq!(file.path, "not a url"),
+            _ => panic!("expected a file_append action"),
+        }
+    }
+}